@@ -35,7 +35,7 @@
 
 extern crate rand;
 
-use std::iter::{FromIterator, Iterator, IntoIterator};
+use std::iter::{Extend, FromIterator, Iterator, IntoIterator};
 use rand::{Rng, ThreadRng, thread_rng};
 use rand::distributions::{Range, IndependentSample};
 
@@ -82,6 +82,39 @@ impl<T> FromIterator<(f32, T)> for RouletteWheel<T> {
     }
 }
 
+impl<T> Extend<(f32, T)> for RouletteWheel<T> {
+    fn extend<A>(&mut self, iter: A) where A: IntoIterator<Item=(f32, T)> {
+        for (fitness, individual) in iter {
+            self.push(fitness, individual);
+        }
+    }
+}
+
+impl<T> Default for RouletteWheel<T> {
+    fn default() -> RouletteWheel<T> {
+        RouletteWheel::new()
+    }
+}
+
+impl<'a, T: Clone> From<&'a [T]> for RouletteWheel<T> {
+    /// Builds a uniformly-weighted wheel (every individual gets a fitness
+    /// of `1.0`) from a plain slice.
+    /// # Example
+    ///
+    /// ```
+    /// use roulette_wheel::RouletteWheel;
+    ///
+    /// let individuals = [10, 15, 20];
+    /// let rw = RouletteWheel::from(&individuals[..]);
+    ///
+    /// assert_eq!(rw.len(), 3);
+    /// assert_eq!(rw.total_fitness(), 3.0);
+    /// ```
+    fn from(slice: &'a [T]) -> RouletteWheel<T> {
+        slice.iter().cloned().map(|individual| (1.0, individual)).collect()
+    }
+}
+
 impl<T> RouletteWheel<T> {
     /// create a new empty random-wheel.
     /// # Example
@@ -185,7 +218,11 @@ impl<T> RouletteWheel<T> {
         self.population.clear();
     }
 
-    /// Add an element associated with a probability.
+    /// Add an element associated with a probability. Returns the index the
+    /// individual was stored at, which can be handed to `get`, `set_fitness`
+    /// or `remove` to manage it afterwards. As with `Vec::swap_remove`,
+    /// removing a *different* index can relocate the last element, so treat
+    /// an index as stable only until the next `remove`.
     /// # Example
     ///
     /// ```
@@ -195,14 +232,16 @@ impl<T> RouletteWheel<T> {
     ///
     /// rw.push(1.0, 'r');
     /// rw.push(1.0, 'c');
-    /// rw.push(1.0, 'a');
+    /// let a = rw.push(1.0, 'a');
     ///
     /// assert_eq!(rw.len(), 3);
+    /// assert_eq!(rw.get(a), Some(&'a'));
     /// ```
-    pub fn push(&mut self, fitness: f32, individual: T) {
+    pub fn push(&mut self, fitness: f32, individual: T) -> usize {
         assert!(fitness >= 0.0, "Can't push the less than zero fitness: {:?}", fitness);
         assert!((self.total_fitness + fitness).is_finite(), "Fitnesses sum reached a non-finite value!");
-        unsafe { self.unchecked_push(fitness, individual) }
+        unsafe { self.unchecked_push(fitness, individual) };
+        self.population.len() - 1
     }
 
     /// Add an element associated with a probability.
@@ -245,9 +284,269 @@ impl<T> RouletteWheel<T> {
         self.total_fitness
     }
 
+    /// Returns a reference to the individual stored at `index`, or `None` if
+    /// it's out of bounds.
+    /// # Example
+    ///
+    /// ```
+    /// use roulette_wheel::RouletteWheel;
+    ///
+    /// let mut rw = RouletteWheel::new();
+    /// let r = rw.push(1.0, 'r');
+    ///
+    /// assert_eq!(rw.get(r), Some(&'r'));
+    /// assert_eq!(rw.get(42), None);
+    /// ```
+    pub fn get(&self, index: usize) -> Option<&T> {
+        self.population.get(index)
+    }
+
+    /// Re-weights the individual at `index`, adjusting `total_fitness` by
+    /// the delta between the old and new fitness. Returns the old fitness,
+    /// or `None` if `index` is out of bounds, matching `get`/`remove`.
+    /// # Example
+    ///
+    /// ```
+    /// use roulette_wheel::RouletteWheel;
+    ///
+    /// let mut rw = RouletteWheel::new();
+    /// let r = rw.push(1.0, 'r');
+    ///
+    /// assert_eq!(rw.set_fitness(r, 3.0), Some(1.0));
+    /// assert_eq!(rw.total_fitness(), 3.0);
+    /// assert_eq!(rw.set_fitness(42, 3.0), None);
+    /// ```
+    pub fn set_fitness(&mut self, index: usize, fitness: f32) -> Option<f32> {
+        if index >= self.fitnesses.len() {
+            return None;
+        }
+
+        assert!(fitness >= 0.0, "Can't set the less than zero fitness: {:?}", fitness);
+        let old_fitness = self.fitnesses[index];
+        let delta = fitness - old_fitness;
+        assert!((self.total_fitness + delta).is_finite(), "Fitnesses sum reached a non-finite value!");
+        self.fitnesses[index] = fitness;
+        self.total_fitness += delta;
+
+        Some(old_fitness)
+    }
+
+    /// Removes the individual at `index`, returning its `(fitness, T)` pair,
+    /// or `None` if `index` is out of bounds. Uses `swap_remove`, so the
+    /// individual previously at the last index now lives at `index`.
+    /// # Example
+    ///
+    /// ```
+    /// use roulette_wheel::RouletteWheel;
+    ///
+    /// let mut rw = RouletteWheel::new();
+    /// rw.push(1.0, 'r');
+    /// let c = rw.push(2.0, 'c');
+    ///
+    /// assert_eq!(rw.remove(c), Some((2.0, 'c')));
+    /// assert_eq!(rw.len(), 1);
+    /// assert_eq!(rw.total_fitness(), 1.0);
+    /// ```
+    pub fn remove(&mut self, index: usize) -> Option<(f32, T)> {
+        if index >= self.population.len() {
+            return None;
+        }
+
+        let fitness = self.fitnesses.swap_remove(index);
+        let individual = self.population.swap_remove(index);
+        self.total_fitness -= fitness;
+
+        Some((fitness, individual))
+    }
+
+    /// Samples one individual without consuming it, unlike `select_iter()`
+    /// which drains as it goes.
+    /// # Example
+    ///
+    /// ```
+    /// extern crate rand;
+    ///
+    /// use rand::thread_rng;
+    /// use roulette_wheel::RouletteWheel;
+    ///
+    /// let rw: RouletteWheel<_> = [(0.1, 10), (0.2, 15), (0.7, 20)].iter().cloned().collect();
+    ///
+    /// let mut rng = thread_rng();
+    /// let (fitness, individual) = rw.peek(&mut rng).unwrap();
+    ///
+    /// assert_eq!(rw.len(), 3);
+    /// ```
+    pub fn peek<R: Rng>(&self, rng: &mut R) -> Option<(f32, &T)> {
+        if self.is_empty() {
+            return None;
+        }
+
+        let sample = Range::new(0.0, 1.0).ind_sample(rng);
+        let mut selection = sample * self.total_fitness;
+        let index = self.fitnesses.iter().position(|&fit| {
+                        selection -= fit;
+                        selection <= 0.0
+                    }).unwrap();
+
+        Some((self.fitnesses[index], &self.population[index]))
+    }
+
     pub fn select_iter(&self) -> SelectIter<ThreadRng, T> {
         SelectIter::<ThreadRng, _>::new(&self)
     }
+
+    /// Returns an iterator that draws individuals *with* replacement in O(1)
+    /// per draw, using Vose's alias method (the alias table itself is built
+    /// in O(n) when the iterator is created).
+    ///
+    /// Unlike `select_iter()`/`into_iter()`, which drain the wheel and thus
+    /// never return the same individual twice, this iterator samples each
+    /// individual independently according to its fitness every time, so a
+    /// fit individual can recur. On a non-empty wheel it never runs out on
+    /// its own: call `.take(n)` to draw a fixed number of individuals. On an
+    /// empty wheel it yields nothing.
+    /// # Example
+    ///
+    /// ```
+    /// use roulette_wheel::RouletteWheel;
+    ///
+    /// let rw: RouletteWheel<_> = [(0.1, 10), (0.2, 15), (0.7, 20)].iter().cloned().collect();
+    ///
+    /// let drawn: Vec<_> = rw.sample_with_replacement().take(10).collect();
+    ///
+    /// assert_eq!(drawn.len(), 10);
+    /// ```
+    pub fn sample_with_replacement(&self) -> AliasIter<ThreadRng, T> {
+        AliasIter::<ThreadRng, _>::new(&self)
+    }
+
+    /// Like `select_iter()`, drains the wheel without replacement, but uses a
+    /// Fenwick (binary indexed) tree internally so draining the whole wheel
+    /// is O(n log n) instead of the O(n²) of `select_iter()`. Worth reaching
+    /// for once populations get large; `select_iter()` remains simpler for
+    /// small ones.
+    /// # Example
+    ///
+    /// ```
+    /// use roulette_wheel::RouletteWheel;
+    ///
+    /// let rw: RouletteWheel<_> = [(0.1, 10), (0.2, 15), (0.7, 20)].iter().cloned().collect();
+    ///
+    /// let drawn: Vec<_> = rw.select_iter_fenwick().collect();
+    ///
+    /// assert_eq!(drawn.len(), 3);
+    /// ```
+    pub fn select_iter_fenwick(&self) -> FenwickSelectIter<ThreadRng, T> {
+        FenwickSelectIter::<ThreadRng, _>::new(&self)
+    }
+
+    /// Selects `n` individuals with a single pass of Stochastic Universal
+    /// Sampling instead of `n` independent fitness-proportionate draws.
+    ///
+    /// Independent draws have high variance: an individual with a 30% share
+    /// may, by chance, never get picked in a given generation. SUS instead
+    /// draws one random offset and lays `n` equally spaced pointers across
+    /// the wheel, guaranteeing every individual is picked at least
+    /// `floor(expected)` times while still sampling with replacement.
+    /// Returns the selected individuals in population order. Yields nothing
+    /// when `n` is `0` or there's no fitness to place pointers against (an
+    /// empty wheel, or one whose individuals all carry zero fitness).
+    /// # Example
+    ///
+    /// ```
+    /// use roulette_wheel::RouletteWheel;
+    ///
+    /// let rw: RouletteWheel<_> = [(0.1, 10), (0.2, 15), (0.7, 20)].iter().cloned().collect();
+    ///
+    /// let parents: Vec<_> = rw.sample_sus(6).collect();
+    ///
+    /// assert_eq!(parents.len(), 6);
+    /// ```
+    pub fn sample_sus(&self, n: usize) -> SusIter<T> {
+        SusIter::new(&self, n)
+    }
+}
+
+/// Builds the `(prob, alias)` tables used by `AliasIter`, following Vose's
+/// alias method: each slot either keeps its own entry outright (`prob = 1`)
+/// or splits its draw between itself and one `alias`ed entry.
+fn build_alias_table(fitnesses: &[f32], total_fitness: f32) -> (Vec<f32>, Vec<usize>) {
+    let n = fitnesses.len();
+    let mut prob = vec![0.0; n];
+    let mut alias = vec![0; n];
+
+    let mut scaled: Vec<f32> = fitnesses.iter()
+        .map(|&fitness| fitness / total_fitness * n as f32)
+        .collect();
+
+    let mut small: Vec<usize> = Vec::new();
+    let mut large: Vec<usize> = Vec::new();
+    for (index, &p) in scaled.iter().enumerate() {
+        if p < 1.0 { small.push(index) } else { large.push(index) }
+    }
+
+    while !small.is_empty() && !large.is_empty() {
+        let l = small.pop().unwrap();
+        let g = large.pop().unwrap();
+        prob[l] = scaled[l];
+        alias[l] = g;
+
+        scaled[g] = (scaled[g] + scaled[l]) - 1.0;
+        if scaled[g] < 1.0 { small.push(g) } else { large.push(g) }
+    }
+
+    for index in small.into_iter().chain(large) {
+        prob[index] = 1.0;
+    }
+
+    (prob, alias)
+}
+
+/// An iterator that samples individuals *with* replacement in O(1) per draw,
+/// backed by a Vose's alias table built once from the wheel's fitnesses.
+///
+/// See `RouletteWheel::sample_with_replacement` for details; unless the wheel
+/// is empty, this iterator never returns `None` on its own.
+pub struct AliasIter<'a, R: Rng, T: 'a> {
+    distribution_range: Range<f32>,
+    rng: R,
+    prob: Vec<f32>,
+    alias: Vec<usize>,
+    roulette_wheel: &'a RouletteWheel<T>
+}
+
+impl<'a, R: Rng, T> AliasIter<'a, R, T> {
+    pub fn new(roulette_wheel: &'a RouletteWheel<T>) -> AliasIter<'a, ThreadRng, T> {
+        AliasIter::from_rng(roulette_wheel, thread_rng())
+    }
+
+    pub fn from_rng(roulette_wheel: &'a RouletteWheel<T>, rng: R) -> AliasIter<'a, R, T> {
+        let (prob, alias) = build_alias_table(&roulette_wheel.fitnesses, roulette_wheel.total_fitness);
+
+        AliasIter {
+            distribution_range: Range::new(0.0, 1.0),
+            rng: rng,
+            prob: prob,
+            alias: alias,
+            roulette_wheel: roulette_wheel
+        }
+    }
+}
+
+impl<'a, R: Rng, T: 'a> Iterator for AliasIter<'a, R, T> {
+    type Item = (f32, &'a T);
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.roulette_wheel.is_empty() {
+            return None;
+        }
+
+        let i = self.rng.gen_range(0, self.prob.len());
+        let coin = self.distribution_range.ind_sample(&mut self.rng);
+        let index = if coin < self.prob[i] { i } else { self.alias[i] };
+
+        Some((self.roulette_wheel.fitnesses[index], &self.roulette_wheel.population[index]))
+    }
 }
 
 pub struct SelectIter<'a, R: Rng, T: 'a> {
@@ -297,6 +596,204 @@ impl<'a, R: Rng, T: 'a> Iterator for SelectIter<'a, R, T> {
     }
 }
 
+/// A 1-indexed Fenwick (binary indexed) tree over `f32` fitnesses, supporting
+/// O(log n) point updates and the O(log n) "smallest index whose prefix sum
+/// exceeds target" query that `FenwickSelectIter` draws with.
+struct FenwickTree {
+    tree: Vec<f32>
+}
+
+impl FenwickTree {
+    fn from_fitnesses(fitnesses: &[f32]) -> FenwickTree {
+        let mut tree = FenwickTree { tree: vec![0.0; fitnesses.len() + 1] };
+        for (index, &fitness) in fitnesses.iter().enumerate() {
+            tree.add(index, fitness);
+        }
+        tree
+    }
+
+    fn add(&mut self, index: usize, delta: f32) {
+        let n = self.tree.len() - 1;
+        let mut i = index + 1;
+        while i <= n {
+            self.tree[i] += delta;
+            i += i & i.wrapping_neg();
+        }
+    }
+
+    /// Returns the smallest index whose prefix sum strictly exceeds `target`,
+    /// clamped to the last valid index so floating-point rounding (or a
+    /// `target` that no remaining entry exceeds, e.g. all-zero fitnesses)
+    /// can never walk past the end of the tree.
+    fn find(&self, mut target: f32) -> usize {
+        let n = self.tree.len() - 1;
+        let mut pos = 0;
+        let mut bit = 1;
+        while bit * 2 <= n { bit *= 2; }
+
+        while bit > 0 {
+            let next = pos + bit;
+            if next <= n && self.tree[next] <= target {
+                pos = next;
+                target -= self.tree[next];
+            }
+            bit /= 2;
+        }
+
+        if pos >= n { n - 1 } else { pos }
+    }
+}
+
+/// Drains the wheel without replacement like `SelectIter`, but in O(n log n)
+/// total thanks to a `FenwickTree` over the fitnesses. See
+/// `RouletteWheel::select_iter_fenwick`.
+pub struct FenwickSelectIter<'a, R: Rng, T: 'a> {
+    distribution_range: Range<f32>,
+    rng: R,
+    total_fitness: f32,
+    remaining: usize,
+    tree: FenwickTree,
+    fitnesses: Vec<f32>,
+    removed: Vec<bool>,
+    roulette_wheel: &'a RouletteWheel<T>
+}
+
+impl<'a, R: Rng, T> FenwickSelectIter<'a, R, T> {
+    pub fn new(roulette_wheel: &'a RouletteWheel<T>) -> FenwickSelectIter<'a, ThreadRng, T> {
+        FenwickSelectIter::from_rng(roulette_wheel, thread_rng())
+    }
+
+    pub fn from_rng(roulette_wheel: &'a RouletteWheel<T>, rng: R) -> FenwickSelectIter<'a, R, T> {
+        FenwickSelectIter {
+            distribution_range: Range::new(0.0, 1.0),
+            rng: rng,
+            total_fitness: roulette_wheel.total_fitness,
+            remaining: roulette_wheel.len(),
+            tree: FenwickTree::from_fitnesses(&roulette_wheel.fitnesses),
+            fitnesses: roulette_wheel.fitnesses.clone(),
+            removed: vec![false; roulette_wheel.len()],
+            roulette_wheel: roulette_wheel
+        }
+    }
+}
+
+impl<'a, R: Rng, T: 'a> Iterator for FenwickSelectIter<'a, R, T> {
+    type Item = (f32, &'a T);
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        (self.remaining, Some(self.remaining))
+    }
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.remaining == 0 {
+            return None;
+        }
+
+        // With a non-positive remaining total, every surviving individual
+        // has zero fitness: fall back to handing them out in index order
+        // instead of sampling a tree that has nothing left to distinguish
+        // them by.
+        let index = if self.total_fitness <= 0.0 {
+            (0..self.removed.len()).find(|&i| !self.removed[i]).unwrap()
+        } else {
+            let sample = self.distribution_range.ind_sample(&mut self.rng);
+            let target = sample * self.total_fitness;
+            self.tree.find(target)
+        };
+
+        let fitness = self.fitnesses[index];
+        self.tree.add(index, -fitness);
+        self.total_fitness -= fitness;
+        self.remaining -= 1;
+        self.removed[index] = true;
+
+        Some((fitness, &self.roulette_wheel.population[index]))
+    }
+}
+
+/// Yields `n` individuals in population order via Stochastic Universal
+/// Sampling. See `RouletteWheel::sample_sus`.
+pub struct SusIter<'a, T: 'a> {
+    roulette_wheel: &'a RouletteWheel<T>,
+    spacing: f32,
+    next_pointer: f32,
+    emitted: usize,
+    n: usize,
+    index: usize,
+    cumulative: f32
+}
+
+impl<'a, T> SusIter<'a, T> {
+    pub fn new(roulette_wheel: &'a RouletteWheel<T>, n: usize) -> SusIter<'a, T> {
+        SusIter::from_rng(roulette_wheel, n, thread_rng())
+    }
+
+    pub fn from_rng<R: Rng>(roulette_wheel: &'a RouletteWheel<T>, n: usize, mut rng: R) -> SusIter<'a, T> {
+        // With no pointers to place or no fitness to place them against,
+        // there's nothing to sample; mark as already-exhausted rather than
+        // feeding `Range::new` a zero-or-negative span.
+        if n == 0 || roulette_wheel.total_fitness <= 0.0 {
+            return SusIter {
+                roulette_wheel: roulette_wheel,
+                spacing: 0.0,
+                next_pointer: 0.0,
+                emitted: n,
+                n: n,
+                index: 0,
+                cumulative: 0.0
+            };
+        }
+
+        let spacing = roulette_wheel.total_fitness / n as f32;
+        let r = Range::new(0.0, spacing).ind_sample(&mut rng);
+
+        SusIter {
+            roulette_wheel: roulette_wheel,
+            spacing: spacing,
+            next_pointer: r,
+            emitted: 0,
+            n: n,
+            index: 0,
+            cumulative: 0.0
+        }
+    }
+}
+
+impl<'a, T: 'a> Iterator for SusIter<'a, T> {
+    type Item = (f32, &'a T);
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        (self.n - self.emitted, Some(self.n - self.emitted))
+    }
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.emitted >= self.n {
+            return None;
+        }
+
+        let len = self.roulette_wheel.len();
+        while self.index < len {
+            let fitness = self.roulette_wheel.fitnesses[self.index];
+            let reached = self.cumulative + fitness;
+
+            // The last individual satisfies any pointer still owed, even if
+            // repeatedly adding `spacing` drifted it past `total_fitness`
+            // due to floating-point error; otherwise a late pointer could
+            // run the loop dry and silently yield fewer than `n` items.
+            if reached > self.next_pointer || self.index == len - 1 {
+                self.emitted += 1;
+                self.next_pointer += self.spacing;
+                return Some((fitness, &self.roulette_wheel.population[self.index]));
+            }
+
+            self.cumulative = reached;
+            self.index += 1;
+        }
+
+        None
+    }
+}
+
 impl<T> IntoIterator for RouletteWheel<T> {
     type Item = (f32, T);
     type IntoIter = IntoSelectIter<ThreadRng, T>;
@@ -354,11 +851,95 @@ impl<R: Rng, T> Iterator for IntoSelectIter<R, T> {
     }
 }
 
+/// Either a full mating pair or, from `couples_or_last()`, the odd parent
+/// left over at the end of a selection stream.
+pub enum Couple<A> {
+    Pair(A, A),
+    Single(A)
+}
+
+/// Pairs up a selection stream for crossover, without collecting into a
+/// `Vec` first. See `SelectionCouples::couples`.
+pub struct Couples<I: Iterator> {
+    iter: I
+}
+
+impl<I: Iterator> Iterator for Couples<I> {
+    type Item = (I::Item, I::Item);
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let mother = match self.iter.next() {
+            Some(mother) => mother,
+            None => return None
+        };
+        let father = match self.iter.next() {
+            Some(father) => father,
+            None => return None
+        };
+        Some((mother, father))
+    }
+}
+
+/// Like `Couples`, but surfaces a trailing unpaired parent as
+/// `Couple::Single` instead of silently dropping it. See
+/// `SelectionCouples::couples_or_last`.
+pub struct CouplesOrLast<I: Iterator> {
+    iter: I
+}
+
+impl<I: Iterator> Iterator for CouplesOrLast<I> {
+    type Item = Couple<I::Item>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let mother = match self.iter.next() {
+            Some(mother) => mother,
+            None => return None
+        };
+        match self.iter.next() {
+            Some(father) => Some(Couple::Pair(mother, father)),
+            None => Some(Couple::Single(mother))
+        }
+    }
+}
+
+/// Adapts a selection stream into mating pairs, so you can write
+/// `for (mother, father) in rw.into_iter().couples() { ... }` without
+/// collecting into a `Vec` first.
+pub trait SelectionCouples: Iterator + Sized {
+    /// Pairs up consecutive selections. An odd trailing parent (no second
+    /// half to pair with) is dropped, mirroring `chunks_exact(2)`.
+    /// # Example
+    ///
+    /// ```
+    /// use roulette_wheel::{RouletteWheel, SelectionCouples};
+    ///
+    /// let rw: RouletteWheel<_> = [(1.0, 10), (1.0, 15), (1.0, 20), (1.0, 25)].iter().cloned().collect();
+    ///
+    /// for ((_, mother), (_, father)) in rw.into_iter().couples() {
+    ///     // do things with the mating pair here
+    /// }
+    /// ```
+    fn couples(self) -> Couples<Self> {
+        Couples { iter: self }
+    }
+
+    /// Like `couples()`, but yields the odd trailing parent as a
+    /// `Couple::Single` instead of dropping it.
+    fn couples_or_last(self) -> CouplesOrLast<Self> {
+        CouplesOrLast { iter: self }
+    }
+}
+
+impl<'a, R: Rng, T> SelectionCouples for SelectIter<'a, R, T> {}
+impl<R: Rng, T> SelectionCouples for IntoSelectIter<R, T> {}
+
 #[cfg(test)]
 mod tests {
     use rand::SeedableRng;
     use rand::StdRng;
-    use {RouletteWheel, SelectIter, IntoSelectIter};
+    use std::iter::Extend;
+    use {RouletteWheel, SelectIter, IntoSelectIter, AliasIter, FenwickSelectIter, SusIter,
+         SelectionCouples, Couple};
 
     const SEED: [usize; 4] = [4, 2, 42, 4242];
 
@@ -400,6 +981,106 @@ mod tests {
         assert_eq!(iter.next(), None);
     }
 
+    #[test]
+    fn test_alias_iter_seeded() {
+        let rng = StdRng::from_seed(&SEED);
+
+        let fitnesses = [0.1, 0.2, 0.3, 0.4, 0.5];
+        let fitnesses = fitnesses.iter().cloned();
+        let population = 15..20;
+        let rw: RouletteWheel<_> = fitnesses.zip(population).collect();
+
+        let mut iter = AliasIter::from_rng(&rw, rng);
+
+        // sampling is with replacement so the wheel keeps handing out draws
+        for _ in 0..20 {
+            let (fitness, &individual) = iter.next().unwrap();
+            assert!(rw.fitnesses.iter().any(|&f| f == fitness));
+            assert!(individual >= 15 && individual < 20);
+        }
+    }
+
+    #[test]
+    fn test_fenwick_select_iter_drains_everything() {
+        let rng = StdRng::from_seed(&SEED);
+
+        let fitnesses = [0.1, 0.2, 0.3, 0.4, 0.5];
+        let fitnesses = fitnesses.iter().cloned();
+        let population = 15..20;
+        let rw: RouletteWheel<_> = fitnesses.zip(population).collect();
+
+        let iter = FenwickSelectIter::from_rng(&rw, rng);
+        let mut drawn: Vec<_> = iter.collect();
+        drawn.sort_by(|a, b| a.1.cmp(b.1));
+
+        assert_eq!(drawn, vec![(0.1, &15), (0.2, &16), (0.3, &17), (0.4, &18), (0.5, &19)]);
+    }
+
+    #[test]
+    fn test_fenwick_select_iter_all_zero_fitness() {
+        let mut rw = RouletteWheel::new();
+        rw.push(0.0, 'a');
+        rw.push(0.0, 'b');
+        rw.push(0.0, 'c');
+
+        let drawn: Vec<_> = rw.select_iter_fenwick().collect();
+
+        assert_eq!(drawn.len(), 3);
+    }
+
+    #[test]
+    fn test_sus_iter_seeded() {
+        let rng = StdRng::from_seed(&SEED);
+
+        let fitnesses = [0.1, 0.2, 0.3, 0.4, 0.5];
+        let fitnesses = fitnesses.iter().cloned();
+        let population = 15..20;
+        let rw: RouletteWheel<_> = fitnesses.zip(population).collect();
+
+        let parents: Vec<_> = SusIter::from_rng(&rw, 10, rng).collect();
+
+        // one pass emits exactly n individuals, in population order
+        assert_eq!(parents.len(), 10);
+        let mut previous_index = None;
+        for (_, individual) in &parents {
+            let index = (*individual - 15) as usize;
+            assert!(previous_index.map_or(true, |prev| index >= prev));
+            previous_index = Some(index);
+        }
+    }
+
+    #[test]
+    fn test_sus_iter_empty_wheel_yields_nothing() {
+        let rw = RouletteWheel::<u8>::new();
+
+        let parents: Vec<_> = rw.sample_sus(3).collect();
+
+        assert_eq!(parents.len(), 0);
+    }
+
+    #[test]
+    fn test_sus_iter_zero_fitness_yields_nothing() {
+        let mut rw = RouletteWheel::new();
+        rw.push(0.0, 'a');
+        rw.push(0.0, 'b');
+
+        let parents: Vec<_> = rw.sample_sus(3).collect();
+
+        assert_eq!(parents.len(), 0);
+    }
+
+    #[test]
+    fn test_sus_iter_large_n_always_yields_n() {
+        // a large `n` against a small population drifts `next_pointer` far
+        // past `total_fitness` through repeated float addition; every draw
+        // must still land somewhere instead of running the population dry.
+        let rw: RouletteWheel<_> = [(0.1, 10), (0.2, 15), (0.7, 20)].iter().cloned().collect();
+
+        let parents: Vec<_> = rw.sample_sus(10_000).collect();
+
+        assert_eq!(parents.len(), 10_000);
+    }
+
     #[test]
     fn test_len() {
         let mut rw = RouletteWheel::<u8>::new();
@@ -413,4 +1094,62 @@ mod tests {
 
         assert_eq!(rw.len(), 4);
     }
+
+    #[test]
+    fn test_extend() {
+        let mut rw: RouletteWheel<_> = [(0.1, 10), (0.2, 15)].iter().cloned().collect();
+
+        rw.extend(vec![(0.3, 20), (0.4, 25)]);
+
+        assert_eq!(rw.len(), 4);
+        assert_eq!(rw.total_fitness(), 1.0);
+    }
+
+    #[test]
+    fn test_get_set_fitness_remove() {
+        let mut rw = RouletteWheel::new();
+        let r = rw.push(1.0, 'r');
+        let c = rw.push(2.0, 'c');
+        rw.push(3.0, 'a');
+
+        assert_eq!(rw.get(c), Some(&'c'));
+
+        assert_eq!(rw.set_fitness(r, 4.0), Some(1.0));
+        assert_eq!(rw.total_fitness(), 9.0);
+
+        // swap_remove relocates the last individual ('a') onto `c`'s slot
+        assert_eq!(rw.remove(c), Some((2.0, 'c')));
+        assert_eq!(rw.get(c), Some(&'a'));
+        assert_eq!(rw.total_fitness(), 7.0);
+
+        assert_eq!(rw.remove(42), None);
+        assert_eq!(rw.set_fitness(42, 1.0), None);
+    }
+
+    #[test]
+    fn test_couples_drops_odd_parent() {
+        let rw: RouletteWheel<_> = [(1.0, 10), (1.0, 15), (1.0, 20)].iter().cloned().collect();
+
+        let pairs: Vec<_> = rw.into_iter().couples().collect();
+
+        assert_eq!(pairs.len(), 1);
+    }
+
+    #[test]
+    fn test_couples_or_last_keeps_odd_parent() {
+        let rw: RouletteWheel<_> = [(1.0, 10), (1.0, 15), (1.0, 20)].iter().cloned().collect();
+
+        let couples: Vec<_> = rw.into_iter().couples_or_last().collect();
+
+        assert_eq!(couples.len(), 2);
+        assert!(match couples[1] { Couple::Single(_) => true, _ => false });
+    }
+
+    #[test]
+    fn test_default() {
+        let rw = RouletteWheel::<u8>::default();
+
+        assert_eq!(rw.len(), 0);
+        assert_eq!(rw.total_fitness(), 0.0);
+    }
 }